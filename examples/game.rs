@@ -11,6 +11,7 @@ fn main() {
         config: RPCConfig {
             app_id: 425407036495495169,
             show_time: true,
+            ..Default::default()
         },
     }));
     app.add_systems(Update, update_presence);
@@ -0,0 +1,50 @@
+use std::marker::PhantomData;
+use std::time::Duration;
+
+use bevy::prelude::*;
+
+use crate::RPCMarker;
+
+/// Configuration for the [`RPCPlugin`]
+#[derive(Resource, Debug, Clone, Copy)]
+pub struct RPCConfig<M: RPCMarker = ()> {
+    /// The Discord application's client id
+    pub app_id: u64,
+    /// Whether to show the elapsed time in the presence
+    pub show_time: bool,
+    /// Delay between the first and second reconnection attempts while disconnected from Discord
+    ///
+    /// The first attempt is made as soon as the client is found disconnected. The delay is
+    /// doubled after every failed attempt, up to [`Self::max_reconnect_interval`].
+    pub reconnect_interval: Duration,
+    /// Upper bound the exponential reconnection backoff is capped at
+    pub max_reconnect_interval: Duration,
+    /// Maximum number of reconnection attempts before giving up, or `None` to retry forever
+    pub max_retries: Option<u32>,
+    /// Ties this configuration to one [`RPCPlugin`] instance; see [`RPCMarker`]
+    pub marker: PhantomData<M>,
+}
+
+impl<M: RPCMarker> Default for RPCConfig<M> {
+    fn default() -> Self {
+        Self {
+            app_id: 0,
+            show_time: false,
+            reconnect_interval: Duration::from_secs(1),
+            max_reconnect_interval: Duration::from_secs(60),
+            max_retries: None,
+            marker: PhantomData,
+        }
+    }
+}
+
+/// A Bevy plugin that allows the developer to interact with the Discord Presence API with ease
+///
+/// The `M` marker type parameter scopes this plugin instance's resources and events, so that
+/// more than one `RPCPlugin` can be registered at once — see [`RPCMarker`] for how to run
+/// several simultaneous activities, each under its own Discord application id.
+#[derive(Debug, Clone, Copy)]
+pub struct RPCPlugin<M: RPCMarker = ()> {
+    /// The configuration used to set up the [`Client`](crate::Client)
+    pub config: RPCConfig<M>,
+}
@@ -0,0 +1,108 @@
+use std::marker::PhantomData;
+use std::time::Duration;
+
+use bevy::{log::prelude::*, prelude::*};
+
+use crate::{events::DiscordPresenceEventKind, Client, DiscordPresenceEvent, RPCConfig, RPCMarker};
+
+/// The lifecycle state of the connection to the local Discord client
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum ConnectionStatus {
+    /// No connection to Discord is currently established; the reconnection system will retry
+    /// until [`RPCConfig::max_retries`] attempts have been made
+    #[default]
+    Disconnected,
+    /// A connection attempt has been started and we're waiting to hear back from Discord
+    Connecting,
+    /// The client is connected and ready to update presence
+    Ready,
+}
+
+/// Tracks the connection lifecycle of one [`RPCPlugin`](crate::RPCPlugin) instance
+#[derive(Resource, Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct ConnectionState<M: RPCMarker = ()> {
+    /// The current connection status
+    pub status: ConnectionStatus,
+    marker: PhantomData<M>,
+}
+
+/// Tracks the exponential backoff used by [`reconnect_client`]
+#[derive(Resource, Debug, Clone, Copy)]
+pub(crate) struct ReconnectTimer<M: RPCMarker = ()> {
+    /// Time remaining before the next `client.start()` attempt
+    remaining: Duration,
+    /// The delay that will be used for the attempt after the next one
+    next_interval: Duration,
+    /// Number of attempts made so far since the last successful connection
+    attempts: u32,
+    marker: PhantomData<M>,
+}
+
+impl<M: RPCMarker> ReconnectTimer<M> {
+    pub(crate) fn new(config: &RPCConfig<M>) -> Self {
+        Self {
+            remaining: Duration::ZERO,
+            next_interval: config.reconnect_interval,
+            attempts: 0,
+            marker: PhantomData,
+        }
+    }
+}
+
+/// Updates [`ConnectionState`] by observing the `Ready`/`Connected`/`Disconnected` events bridged
+/// from the underlying client
+pub(crate) fn update_connection_state<M: RPCMarker>(
+    mut events: EventReader<DiscordPresenceEvent<M>>,
+    mut connection: ResMut<ConnectionState<M>>,
+) {
+    for event in events.read() {
+        match event.kind {
+            DiscordPresenceEventKind::Ready(_) | DiscordPresenceEventKind::Connected(_) => {
+                connection.status = ConnectionStatus::Ready;
+            }
+            DiscordPresenceEventKind::Disconnected(_) => {
+                connection.status = ConnectionStatus::Disconnected;
+            }
+            _ => {}
+        }
+    }
+}
+
+/// While disconnected, retries `client.start()` on an exponential backoff capped at
+/// [`RPCConfig::max_reconnect_interval`], so the plugin recovers if Discord is launched after
+/// the game, or after a transient outage
+pub(crate) fn reconnect_client<M: RPCMarker>(
+    time: Res<Time>,
+    config: Res<RPCConfig<M>>,
+    mut connection: ResMut<ConnectionState<M>>,
+    mut timer: ResMut<ReconnectTimer<M>>,
+    mut client: ResMut<Client<M>>,
+) {
+    if connection.status != ConnectionStatus::Disconnected {
+        *timer = ReconnectTimer::new(&config);
+        return;
+    }
+
+    if let Some(max_retries) = config.max_retries {
+        if timer.attempts >= max_retries {
+            return;
+        }
+    }
+
+    timer.remaining = timer.remaining.saturating_sub(time.delta());
+
+    if !timer.remaining.is_zero() {
+        return;
+    }
+
+    debug!("Attempting to reconnect to Discord (attempt {})", timer.attempts + 1);
+
+    match client.start() {
+        Ok(()) => connection.status = ConnectionStatus::Connecting,
+        Err(why) => debug!("Reconnection attempt failed: {}", why),
+    }
+
+    timer.attempts += 1;
+    timer.remaining = timer.next_interval;
+    timer.next_interval = (timer.next_interval * 2).min(config.max_reconnect_interval);
+}
@@ -0,0 +1,115 @@
+use std::marker::PhantomData;
+
+use bevy::prelude::*;
+use discord_presence::Event as DiscordEvent;
+use serde_json::Value;
+
+use crate::{ActivityState, RPCMarker};
+
+/// The payload carried by a [`DiscordPresenceEvent`]
+///
+/// Bridged from [`discord_presence::Event`] into Bevy's typed event system by
+/// [`drain_discord_events`], which runs in [`First`] so that, like Bevy's own
+/// `event_update_system`, no event is dropped across a frame boundary before a game system gets
+/// to read it.
+#[derive(Debug, Clone)]
+pub enum DiscordPresenceEventKind {
+    /// The client has connected to the local Discord RPC socket
+    Ready(Value),
+    /// The client has (re)established its connection
+    Connected(Value),
+    /// The client has lost its connection to Discord
+    Disconnected(Value),
+    /// A friend accepted an invite to join the player's activity
+    ActivityJoin {
+        /// The secret required to join the activity, as set in `ActivityState::join_secret`
+        secret: String,
+    },
+    /// A friend accepted an invite to spectate the player's activity
+    ActivitySpectate {
+        /// The secret required to spectate the activity
+        secret: String,
+    },
+    /// A friend has asked to join the player's activity and is awaiting approval
+    ActivityJoinRequest {
+        /// The Discord user id of the requester
+        user_id: String,
+        /// The requester's username
+        username: String,
+    },
+    /// Any other event, for variants without a strongly-typed payload
+    Other {
+        /// The underlying event kind
+        event: DiscordEvent,
+        /// The raw JSON payload, if any
+        payload: Value,
+    },
+}
+
+impl DiscordPresenceEventKind {
+    /// Builds a [`DiscordPresenceEventKind`] from a raw `(Event, payload)` pair reported by the client
+    fn from_raw(event: DiscordEvent, payload: Value) -> Self {
+        match event {
+            DiscordEvent::Ready => Self::Ready(payload),
+            DiscordEvent::Connected => Self::Connected(payload),
+            DiscordEvent::Disconnected => Self::Disconnected(payload),
+            DiscordEvent::ActivityJoin => match payload.get("secret").and_then(Value::as_str) {
+                Some(secret) => Self::ActivityJoin {
+                    secret: secret.to_string(),
+                },
+                None => Self::Other { event, payload },
+            },
+            DiscordEvent::ActivitySpectate => match payload.get("secret").and_then(Value::as_str) {
+                Some(secret) => Self::ActivitySpectate {
+                    secret: secret.to_string(),
+                },
+                None => Self::Other { event, payload },
+            },
+            DiscordEvent::ActivityJoinRequest => {
+                let user = payload.get("user");
+                let user_id = user.and_then(|u| u.get("id")).and_then(Value::as_str);
+                let username = user.and_then(|u| u.get("username")).and_then(Value::as_str);
+
+                match (user_id, username) {
+                    (Some(user_id), Some(username)) => Self::ActivityJoinRequest {
+                        user_id: user_id.to_string(),
+                        username: username.to_string(),
+                    },
+                    _ => Self::Other { event, payload },
+                }
+            }
+            _ => Self::Other { event, payload },
+        }
+    }
+}
+
+/// A Discord presence event, bridged from [`discord_presence::Event`] into Bevy's typed event
+/// system; see [`DiscordPresenceEventKind`] for the event itself
+///
+/// The `M` marker ties this event to a specific [`RPCPlugin`](crate::RPCPlugin) instance, so
+/// `EventReader<DiscordPresenceEvent<M>>` only observes events from that instance's client.
+#[derive(Event, Debug, Clone)]
+pub struct DiscordPresenceEvent<M: RPCMarker = ()> {
+    /// The event that occurred
+    pub kind: DiscordPresenceEventKind,
+    marker: PhantomData<M>,
+}
+
+/// Drains the queue of raw Discord events and re-emits them as [`DiscordPresenceEvent`]s
+///
+/// Scheduled in [`First`] (mirroring Bevy's own `event_update_system`) so that events fired
+/// during the current frame are visible to every `Update` system before they'd otherwise be
+/// dropped.
+pub(crate) fn drain_discord_events<M: RPCMarker>(
+    activity: Res<ActivityState<M>>,
+    mut writer: EventWriter<DiscordPresenceEvent<M>>,
+) {
+    let mut queue = activity.events.lock().expect("event queue mutex poisoned");
+
+    for (event, payload) in queue.0.drain(..) {
+        writer.send(DiscordPresenceEvent {
+            kind: DiscordPresenceEventKind::from_raw(event, payload),
+            marker: PhantomData,
+        });
+    }
+}
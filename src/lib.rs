@@ -12,12 +12,13 @@
 //! fn main() {
 //!     println!("hello world!");
 //!     let mut app = App::new();
-//!     app.add_plugins(( 
-//!         DefaultPlugins, 
+//!     app.add_plugins((
+//!         DefaultPlugins,
 //!         RPCPlugin {
 //!             config: RPCConfig {
 //!                 app_id: 425407036495495169,
 //!                 show_time: true,
+//!                 ..Default::default()
 //!             }
 //!         }
 //!     ));
@@ -30,7 +31,16 @@
 //!     state.details = Some("Hello World".to_string());
 //! }
 //! ```
+//!
+//! # Running more than one activity
+//!
+//! A single [`RPCPlugin`] is keyed by the unit marker `()`, so [`ActivityState`], [`Client`] and
+//! the rest of its resources resolve unambiguously by default. To drive more than one Discord
+//! application at once, give each [`RPCPlugin`] instance its own zero-sized marker type and use
+//! it to parameterize the resources you interact with, e.g. `ResMut<ActivityState<Launcher>>`.
+//! See [`RPCMarker`] for the trait a marker type needs to implement.
 
+use std::marker::PhantomData;
 use std::time::{SystemTime, UNIX_EPOCH};
 
 use bevy::{log::prelude::*, prelude::*};
@@ -38,41 +48,97 @@ use discord_presence::{models::ActivityTimestamps, Client as DiscordClient, Even
 
 /// The Discord configuration
 pub mod config;
+/// The connection lifecycle and automatic reconnection
+mod connection;
+/// Bevy events bridged from the underlying Discord client's callbacks
+mod events;
 /// The state that holds the Discord activity
 mod state;
 
+/// A marker type used to key one [`RPCPlugin`] instance's resources and events
+///
+/// Implemented for `()`, which is used when only a single [`RPCPlugin`] is registered. To run
+/// more than one side by side, define a zero-sized marker struct per instance and derive the
+/// handful of traits this bound requires, e.g.:
+///
+/// ```rust
+/// use bevy_discord_presence::RPCMarker;
+///
+/// #[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+/// struct Launcher;
+///
+/// impl RPCMarker for Launcher {}
+/// ```
+pub trait RPCMarker: Send + Sync + Clone + Copy + std::fmt::Debug + Default + PartialEq + Eq + 'static {}
+
+impl RPCMarker for () {}
+
 /// A wrapper around the internal [`discord_presence::Client`] struct that implements [`bevy::prelude::Resource`]
-#[derive(Resource, derive_more::Deref, derive_more::DerefMut)]
-pub struct Client(DiscordClient);
+///
+/// Deref/DerefMut to the inner [`discord_presence::Client`] are implemented by hand rather than
+/// derived, since the `marker` field means this is no longer a single-field newtype.
+#[derive(Resource)]
+pub struct Client<M: RPCMarker = ()> {
+    client: DiscordClient,
+    marker: PhantomData<M>,
+}
 
-impl Client {
+impl<M: RPCMarker> Client<M> {
     /// Instantiates a [`Client`] struct
     ///
     /// Wraps the internal [`discord_presence::Client`] struct
     pub fn new(client_id: u64) -> Self {
-        Client(DiscordClient::new(client_id))
+        Client {
+            client: DiscordClient::new(client_id),
+            marker: PhantomData,
+        }
+    }
+}
+
+impl<M: RPCMarker> std::ops::Deref for Client<M> {
+    type Target = DiscordClient;
+
+    fn deref(&self) -> &Self::Target {
+        &self.client
+    }
+}
+
+impl<M: RPCMarker> std::ops::DerefMut for Client<M> {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.client
     }
 }
 
 pub use config::{RPCConfig, RPCPlugin};
-pub use state::ActivityState;
+pub use connection::{ConnectionState, ConnectionStatus};
+pub use events::{DiscordPresenceEvent, DiscordPresenceEventKind};
+pub use state::{ActivityState, ActivityStateButton};
 
 /// Implements the Bevy plugin trait
-impl Plugin for RPCPlugin {
+impl<M: RPCMarker> Plugin for RPCPlugin<M> {
     fn build(&self, app: &mut App) {
         let client_config = self.config;
 
         // NOTE: I am aware this is deprecated
         // For now, for the sake of backwards compatability with old Bevy versions we will keep using this
         // If Bevy removes these functions in future, this will change
-        app.add_systems(Startup, startup_client);
-        app.add_systems(Update, check_activity_changed);
+        app.add_systems(Startup, startup_client::<M>);
+        app.add_systems(First, events::drain_discord_events::<M>);
+        app.add_systems(
+            Update,
+            (connection::update_connection_state::<M>, connection::reconnect_client::<M>).chain(),
+        );
+        app.add_systems(Update, check_activity_changed::<M>);
         debug!("Added systems");
 
-        app.insert_resource::<RPCConfig>(client_config);
+        app.add_event::<DiscordPresenceEvent<M>>();
 
-        app.init_resource::<ActivityState>();
-        app.insert_resource::<Client>(Client::new(client_config.app_id));
+        app.insert_resource::<RPCConfig<M>>(client_config);
+
+        app.init_resource::<ActivityState<M>>();
+        app.insert_resource::<Client<M>>(Client::new(client_config.app_id));
+        app.init_resource::<ConnectionState<M>>();
+        app.insert_resource(connection::ReconnectTimer::new(&client_config));
 
         debug!("Initialized resources");
     }
@@ -83,10 +149,11 @@ impl Plugin for RPCPlugin {
 }
 
 /// Initializes the client and starts it running
-fn startup_client(
-    mut activity: ResMut<ActivityState>,
-    mut client: ResMut<Client>,
-    config: Res<RPCConfig>,
+fn startup_client<M: RPCMarker>(
+    mut activity: ResMut<ActivityState<M>>,
+    mut client: ResMut<Client<M>>,
+    mut connection: ResMut<ConnectionState<M>>,
+    config: Res<RPCConfig<M>>,
 ) {
     use quork::traits::list::ListVariants;
 
@@ -106,19 +173,28 @@ fn startup_client(
         client.on_event(event, {
             let events = activity.events.clone();
 
-            move |_| {
-                events.lock().0.push_back(event);
+            move |payload| {
+                events
+                    .lock()
+                    .expect("event queue mutex poisoned")
+                    .0
+                    .push_back((event, payload));
                 debug!("Added event: {:?}", event);
             }
         });
     }
 
-    _ = client.start();
-    debug!("Client has started");
+    match client.start() {
+        Ok(()) => {
+            connection.status = connection::ConnectionStatus::Connecting;
+            debug!("Client has started");
+        }
+        Err(why) => error!("Failed to start Discord client: {}", why),
+    }
 }
 
 /// Runs whenever the activity has been changed, and at startup
-fn check_activity_changed(activity: Res<ActivityState>, mut client: ResMut<Client>) {
+fn check_activity_changed<M: RPCMarker>(activity: Res<ActivityState<M>>, mut client: ResMut<Client<M>>) {
     if activity.is_changed() {
         let res = client.set_activity(|_| activity.clone().into());
 
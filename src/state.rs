@@ -0,0 +1,140 @@
+use std::collections::VecDeque;
+use std::marker::PhantomData;
+use std::sync::{Arc, Mutex};
+
+use bevy::{log::prelude::*, prelude::*};
+use discord_presence::{
+    models::{Activity, ActivityAssets, ActivityButton, ActivityParty, ActivitySecrets, ActivityTimestamps},
+    Event,
+};
+use serde_json::Value;
+
+use crate::RPCMarker;
+
+/// FIFO queue of raw `(Event, payload)` pairs received from Discord
+///
+/// Shared between the client's callback thread (which pushes) and the Bevy event-bridge system
+/// (which drains), via the [`Mutex`] wrapping it on [`ActivityState::events`].
+#[derive(Default)]
+pub struct EventQueue(pub(crate) VecDeque<(Event, Value)>);
+
+/// A clickable action button shown on the activity card
+///
+/// Discord allows at most two of these per activity; any beyond the first two are dropped when
+/// converting to [`Activity`].
+#[derive(Debug, Clone)]
+pub struct ActivityStateButton {
+    /// The text shown on the button
+    pub label: String,
+    /// The URL opened when the button is clicked
+    pub url: String,
+}
+
+/// The state that holds the Discord activity
+///
+/// The `M` marker ties this state to a specific [`RPCPlugin`](crate::RPCPlugin) instance, so
+/// several plugins can each drive their own activity without colliding.
+#[derive(Resource, Clone, Default)]
+pub struct ActivityState<M: RPCMarker = ()> {
+    /// The first line of the activity's details
+    pub details: Option<String>,
+    /// The second line of the activity, shown as the "state"
+    pub state: Option<String>,
+    /// Whether this is an instanced activity (e.g. a match)
+    pub instance: Option<bool>,
+    /// Start/end timestamps shown as elapsed/remaining time
+    pub timestamps: Option<ActivityTimestamps>,
+    /// Key of the large image asset, as uploaded to the app's Rich Presence art assets
+    pub large_image: Option<String>,
+    /// Tooltip text shown when hovering the large image
+    pub large_text: Option<String>,
+    /// Key of the small image asset, overlaid on the large image
+    pub small_image: Option<String>,
+    /// Tooltip text shown when hovering the small image
+    pub small_text: Option<String>,
+    /// Id of the party this activity belongs to
+    pub party_id: Option<String>,
+    /// Current and maximum size of the party, shown as e.g. "2 of 4"
+    pub party_size: Option<(i32, i32)>,
+    /// Secret used by Discord to let a friend join this party
+    pub join_secret: Option<String>,
+    /// Secret used by Discord to let a friend spectate this match
+    pub spectate_secret: Option<String>,
+    /// Secret used by Discord to identify a unique match, shown to friends via the party
+    pub match_secret: Option<String>,
+    /// Action buttons shown on the activity card (max two, see [`ActivityStateButton`])
+    pub buttons: Vec<ActivityStateButton>,
+    /// Raw events received from Discord, drained once per frame by the event-bridge system
+    pub(crate) events: Arc<Mutex<EventQueue>>,
+    marker: PhantomData<M>,
+}
+
+impl<M: RPCMarker> From<ActivityState<M>> for Activity {
+    fn from(state: ActivityState<M>) -> Self {
+        let assets = if state.large_image.is_some()
+            || state.large_text.is_some()
+            || state.small_image.is_some()
+            || state.small_text.is_some()
+        {
+            Some(ActivityAssets {
+                large_image: state.large_image,
+                large_text: state.large_text,
+                small_image: state.small_image,
+                small_text: state.small_text,
+                ..Default::default()
+            })
+        } else {
+            None
+        };
+
+        let party = if state.party_id.is_some() || state.party_size.is_some() {
+            Some(ActivityParty {
+                id: state.party_id,
+                size: state.party_size,
+                ..Default::default()
+            })
+        } else {
+            None
+        };
+
+        let secrets = if state.join_secret.is_some() || state.spectate_secret.is_some() || state.match_secret.is_some() {
+            Some(ActivitySecrets {
+                join: state.join_secret,
+                spectate: state.spectate_secret,
+                match_: state.match_secret,
+                ..Default::default()
+            })
+        } else {
+            None
+        };
+
+        if state.buttons.len() > 2 {
+            warn!(
+                "ActivityState has {} buttons but Discord only supports 2; dropping the rest",
+                state.buttons.len()
+            );
+        }
+
+        let buttons: Vec<_> = state
+            .buttons
+            .into_iter()
+            .take(2)
+            .map(|button| ActivityButton {
+                label: button.label,
+                url: button.url,
+            })
+            .collect();
+
+        Activity {
+            details: state.details,
+            state: state.state,
+            instance: state.instance,
+            timestamps: state.timestamps,
+            assets,
+            party,
+            secrets,
+            buttons: (!buttons.is_empty()).then_some(buttons),
+            ..Default::default()
+        }
+    }
+}